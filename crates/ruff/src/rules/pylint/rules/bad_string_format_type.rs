@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
 use ruff_macros::{define_violation, derive_message_formats};
-use rustc_hash::FxHashMap;
-use rustpython_common::cformat::{CFormatPart, CFormatSpec, CFormatStrOrBytes, CFormatString};
-use rustpython_parser::ast::{Constant, Expr, ExprKind, Location};
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_common::cformat::{
+    CConversionFlags, CFormatPart, CFormatQuantity, CFormatSpec, CFormatStrOrBytes, CFormatString,
+};
+use rustpython_parser::ast::{Constant, Expr, ExprKind, Keyword, Location};
 use rustpython_parser::lexer;
 use rustpython_parser::lexer::Tok;
 
@@ -15,7 +17,8 @@ use crate::violation::Violation;
 
 define_violation!(
     /// ### What it does
-    /// Checks for mismatched argument types in "old-style" format strings.
+    /// Checks for mismatched argument types in format strings, `str.format()`
+    /// calls, and f-strings.
     ///
     /// ### Why is this bad?
     /// The format string is not checked at compile time, so it is easy to
@@ -24,11 +27,15 @@ define_violation!(
     /// ### Example
     /// ```python
     /// print("%d" % "1")
+    /// print("{:d}".format("1"))
+    /// print(f"{'1':d}")
     /// ```
     ///
     /// Use instead:
     /// ```python
     /// print("%d" % 1)
+    /// print("{:d}".format(1))
+    /// print(f"{1:d}")
     /// ```
     pub struct BadStringFormatType;
 );
@@ -39,6 +46,104 @@ impl Violation for BadStringFormatType {
     }
 }
 
+// `RedundantStringFormatConversion`, `UselessFormatFlags`, and
+// `BadStringFormatArity` below are all reached through `bad_string_format_type`
+// (so they aren't dead code), but none has a `Rule` variant or pylint code:
+// `registry.rs`, which owns that mapping, isn't part of this snapshot.
+define_violation!(
+    /// ### What it does
+    /// Checks for explicit `str()`, `int()`, or `float()` conversions wrapping
+    /// an argument to a `%` format string, where the conversion is already
+    /// performed by the matching format spec.
+    ///
+    /// ### Why is this bad?
+    /// The conversion is redundant: `%s` already calls `str()` on its
+    /// argument, so wrapping it in `str()` adds nothing but clutter. `int()`
+    /// and `float()` are only flagged when the wrapped value is already
+    /// known to be numeric, since `%d` and `%f` reject non-numeric arguments.
+    ///
+    /// ### Example
+    /// ```python
+    /// print("%s" % str(obj))
+    /// ```
+    ///
+    /// Use instead:
+    /// ```python
+    /// print("%s" % obj)
+    /// ```
+    pub struct RedundantStringFormatConversion {
+        pub conversion: String,
+    }
+);
+impl Violation for RedundantStringFormatConversion {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantStringFormatConversion { conversion } = self;
+        format!("Remove redundant `{conversion}()` call; the format spec already performs it")
+    }
+}
+
+define_violation!(
+    /// ### What it does
+    /// Checks for `%` conversion flags that have no effect for their
+    /// conversion type, e.g. the sign (`+`/space), `#`, and `0` flags
+    /// applied to `s`, `r`, or `c`.
+    ///
+    /// ### Why is this bad?
+    /// These flags are silently ignored for string-like conversions, so
+    /// they're either a leftover from editing the format string or a sign
+    /// that the wrong conversion type was used.
+    ///
+    /// ### Example
+    /// ```python
+    /// print("%+s" % "1")
+    /// ```
+    ///
+    /// Use instead:
+    /// ```python
+    /// print("%s" % "1")
+    /// ```
+    pub struct UselessFormatFlags;
+);
+impl Violation for UselessFormatFlags {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Format flag(s) have no effect for the `s`/`r`/`c` conversion type")
+    }
+}
+
+define_violation!(
+    /// ### What it does
+    /// Checks for a mismatch between the number of arguments a `%` format
+    /// string expects and the number its operand supplies, or between the
+    /// mapping keys the template names and the keys a dict operand
+    /// supplies.
+    ///
+    /// ### Why is this bad?
+    /// Under- or over-supplying arguments, or naming a mapping key that
+    /// doesn't exist, raises `TypeError`/`KeyError` at runtime.
+    ///
+    /// ### Example
+    /// ```python
+    /// print("%s %s" % (1,))
+    /// ```
+    ///
+    /// Use instead:
+    /// ```python
+    /// print("%s %s" % (1, 2))
+    /// ```
+    pub struct BadStringFormatArity {
+        pub reason: String,
+    }
+);
+impl Violation for BadStringFormatArity {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let BadStringFormatArity { reason } = self;
+        reason.clone()
+    }
+}
+
 #[derive(Debug)]
 enum DataType {
     String,
@@ -90,6 +195,48 @@ impl From<char> for DataType {
     }
 }
 
+/// Infer a [`DataType`] for expressions that aren't `ExprKind::Constant`, so
+/// that obvious mismatches on variables and expressions can be caught
+/// instead of silently skipped. Falls back to `DataType::Other` for
+/// anything we can't resolve, which callers treat as "unknown, don't flag".
+///
+/// Note: we deliberately don't try to resolve `ExprKind::Name` through the
+/// checker's binding table. A scope only records a name's *latest* binding,
+/// so for `if c: x = 1 else: x = "s"` that would report the `else` branch's
+/// binding as if it were the only one, flagging valid code reached via the
+/// `if` branch. Until that's flow-sensitive, `Name` stays `DataType::Other`.
+fn infer_type(checker: &Checker, expr: &Expr) -> DataType {
+    match &expr.node {
+        ExprKind::Constant { value, .. } => value.into(),
+        ExprKind::BinOp { left, right, .. } => {
+            match (infer_type(checker, left), infer_type(checker, right)) {
+                (DataType::String, _) | (_, DataType::String) => DataType::Other,
+                (DataType::Float, _) | (_, DataType::Float) => DataType::Float,
+                (DataType::Other, _) | (_, DataType::Other) => DataType::Other,
+                _ => DataType::Number,
+            }
+        }
+        ExprKind::Call { func, .. } => {
+            let ExprKind::Name { id, .. } = &func.node else {
+                return DataType::Other;
+            };
+            // Matching by name alone would mistake a shadowed `str`/`int`/
+            // `float` (e.g. `def int(x): ...`) for the real builtin, so
+            // confirm the name isn't rebound in this scope first.
+            if !checker.is_builtin(id) {
+                return DataType::Other;
+            }
+            match id.as_str() {
+                "len" | "ord" | "int" => DataType::Integer,
+                "float" => DataType::Float,
+                "str" | "repr" | "chr" => DataType::String,
+                _ => DataType::Other,
+            }
+        }
+        _ => DataType::Other,
+    }
+}
+
 fn collect_specs(formats: &[CFormatStrOrBytes<String>]) -> Vec<&CFormatSpec> {
     let mut specs = vec![];
     for format in formats {
@@ -102,10 +249,8 @@ fn collect_specs(formats: &[CFormatStrOrBytes<String>]) -> Vec<&CFormatSpec> {
     specs
 }
 
-/// Return `true` if the format string is equivalent to the constant type
-fn equivalent(format: &CFormatSpec, value: &Constant) -> bool {
-    let constant: DataType = value.into();
-    let format: DataType = format.format_char.into();
+/// Return `true` if a value of `constant`'s type can be formatted as `format`.
+fn equivalent_types(constant: &DataType, format: &DataType) -> bool {
     if matches!(format, DataType::String) {
         // We can always format as type `String`.
         return true;
@@ -115,8 +260,134 @@ fn equivalent(format: &CFormatSpec, value: &Constant) -> bool {
         // If the format is not string, we cannot format as type `Other`.
         false
     } else {
-        constant.is_compatible_with(&format)
+        constant.is_compatible_with(format)
+    }
+}
+
+/// Return `true` if the format string is equivalent to the constant type
+fn equivalent(format: &CFormatSpec, value: &Constant) -> bool {
+    equivalent_types(&value.into(), &format.format_char.into())
+}
+
+/// A replacement field parsed out of a new-style (`str.format()`/f-string)
+/// template, e.g. the `0:d` in `"{0:d}"`.
+struct ReplacementField<'a> {
+    /// The field name or index, e.g. `0`, `name`, or the empty string for
+    /// auto-numbered fields.
+    name: &'a str,
+    /// The format spec, if any, e.g. the `d` in `{0:d}`.
+    spec: Option<&'a str>,
+}
+
+/// Parse the replacement fields out of a `str.format()`-style template,
+/// skipping escaped `{{` and `}}` braces.
+fn parse_format_fields(template: &str) -> Vec<ReplacementField> {
+    let mut fields = vec![];
+    let mut chars = template.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '{' {
+            if chars.peek().map(|(_, c)| *c) == Some('{') {
+                chars.next();
+                continue;
+            }
+            let start = i + '{'.len_utf8();
+            let mut depth = 1;
+            let mut end = template.len();
+            for (j, c) in chars.by_ref() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            end = j;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let body = &template[start..end];
+            let (name, spec) = match body.split_once(':') {
+                Some((name, spec)) => (name, Some(spec)),
+                None => (body, None),
+            };
+            // Strip a `!r`/`!s`/`!a` conversion suffix from the field name.
+            let name = name.split('!').next().unwrap_or(name);
+            fields.push(ReplacementField { name, spec });
+        } else if c == '}' && chars.peek().map(|(_, c)| *c) == Some('}') {
+            chars.next();
+        }
+    }
+    fields
+}
+
+/// Return the new-style presentation type character at the end of a format
+/// spec (e.g. `f` in `>10.2f` or `d` in `05d`), if any.
+///
+/// A spec whose prefix (everything but that trailing character) contains
+/// another letter isn't the standard mini-language -- it's almost always a
+/// `__format__`-specific spec such as a strftime pattern (`{:%Y-%m-%d}`,
+/// last char `d`), where the "type char" we'd otherwise read off the end
+/// doesn't mean what it means for `int`/`float`. Bail out in that case
+/// rather than risk a false positive.
+fn new_style_type_char(spec: &str) -> Option<char> {
+    let last = spec.chars().last()?;
+    if !matches!(
+        last,
+        'b' | 'c' | 'd' | 'e' | 'E' | 'f' | 'F' | 'g' | 'G' | 'n' | 'o' | 's' | 'x' | 'X' | '%'
+    ) {
+        return None;
     }
+    let prefix = &spec[..spec.len() - last.len_utf8()];
+    if prefix.chars().any(char::is_alphabetic) {
+        return None;
+    }
+    Some(last)
+}
+
+/// Resolve a replacement field's name against a `str.format()` call's
+/// positional and keyword arguments. Returns `None` for auto-numbered
+/// fields (the caller tracks the counter) or references we can't resolve,
+/// such as attribute or index access (`{0.attr}`, `{0[0]}`).
+fn resolve_format_arg<'a>(
+    name: &str,
+    args: &'a [Expr],
+    keywords: &'a [Keyword],
+) -> Option<&'a Expr> {
+    if name.is_empty() {
+        return None;
+    }
+    if name.contains('.') || name.contains('[') {
+        return None;
+    }
+    if let Ok(index) = name.parse::<usize>() {
+        return args.get(index);
+    }
+    keywords
+        .iter()
+        .find(|keyword| keyword.node.arg.as_deref() == Some(name))
+        .map(|keyword| &keyword.node.value)
+}
+
+/// Join the constant string parts of an f-string's format spec (itself a
+/// nested `JoinedStr`), or return `None` if it contains any non-constant
+/// pieces.
+fn format_spec_as_str(expr: &Expr) -> Option<String> {
+    let ExprKind::JoinedStr { values } = &expr.node else {
+        return None;
+    };
+    let mut spec = String::new();
+    for value in values {
+        let ExprKind::Constant {
+            value: Constant::Str(s),
+            ..
+        } = &value.node
+        else {
+            return None;
+        };
+        spec.push_str(s);
+    }
+    Some(spec)
 }
 
 /// Return `true` if the [`Constnat`] aligns with the format type.
@@ -132,7 +403,7 @@ fn is_valid_constant(formats: &[CFormatStrOrBytes<String>], value: &Constant) ->
 }
 
 /// Return `true` if the tuple elements align with the format types.
-fn is_valid_tuple(formats: &[CFormatStrOrBytes<String>], elts: &[Expr]) -> bool {
+fn is_valid_tuple(checker: &Checker, formats: &[CFormatStrOrBytes<String>], elts: &[Expr]) -> bool {
     let formats = collect_specs(formats);
 
     // If there are more formats that values, the statement is invalid. Avoid
@@ -146,11 +417,14 @@ fn is_valid_tuple(formats: &[CFormatStrOrBytes<String>], elts: &[Expr]) -> bool
             if !equivalent(format, value) {
                 return false;
             }
-        } else if let ExprKind::Name { .. } = &elt.node {
-            continue;
-        } else if format.format_char != 's' {
-            // Non-`ExprKind::Constant` values can only be formatted as strings.
-            return false;
+        } else {
+            let inferred = infer_type(checker, elt);
+            if matches!(inferred, DataType::Other) {
+                continue;
+            }
+            if !equivalent_types(&inferred, &format.format_char.into()) {
+                return false;
+            }
         }
     }
     true
@@ -158,6 +432,7 @@ fn is_valid_tuple(formats: &[CFormatStrOrBytes<String>], elts: &[Expr]) -> bool
 
 /// Return `true` if the dictionary values align with the format types.
 fn is_valid_dict(
+    checker: &Checker,
     formats: &[CFormatStrOrBytes<String>],
     keys: &[Option<Expr>],
     values: &[Expr],
@@ -195,11 +470,14 @@ fn is_valid_dict(
                 if !equivalent(format, value) {
                     return false;
                 }
-            } else if let ExprKind::Name { .. } = &value.node {
-                continue;
-            } else if format.format_char != 's' {
-                // Non-`ExprKind::Constant` values can only be formatted as strings.
-                return false;
+            } else {
+                let inferred = infer_type(checker, value);
+                if matches!(inferred, DataType::Other) {
+                    continue;
+                }
+                if !equivalent_types(&inferred, &format.format_char.into()) {
+                    return false;
+                }
             }
         } else {
             // We can't check non-string keys.
@@ -209,16 +487,240 @@ fn is_valid_dict(
     true
 }
 
-/// Return `true` if the format string is valid for "other" types.
-fn is_valid_other(formats: &[CFormatStrOrBytes<String>]) -> bool {
+/// Return `true` if `format`'s flags are ignored for its conversion type:
+/// the sign (`+`/space), `#`, and `0` flags only affect numeric
+/// conversions, so pairing them with `s`, `r`, or `c` is a silent no-op
+/// (e.g. `%+s`, `%05s`, `%#s`).
+///
+/// Note that a `.precision` on an integer conversion (e.g. `%.3d`) is
+/// *not* flagged here: unlike the flags above, precision on an integer
+/// conversion is meaningful -- it sets the minimum number of digits, zero
+/// padded (`"%.3d" % 5 == "005"`).
+fn has_useless_flags(format: &CFormatSpec) -> bool {
+    if !matches!(format.format_char, 's' | 'r' | 'c') {
+        return false;
+    }
+    let ignored_for_strings = CConversionFlags::SIGN_CHAR
+        | CConversionFlags::BLANK_SIGN
+        | CConversionFlags::ALTERNATE_FORM
+        | CConversionFlags::ZERO_PAD;
+    format.flags.intersects(ignored_for_strings)
+}
+
+/// Return the builtin conversion name (`str`, `int`, `float`) and the
+/// wrapped argument if `expr` is exactly a call to one of those builtins
+/// with a single positional argument, e.g. `str(x)`.
+fn redundant_conversion_call<'a>(checker: &Checker, expr: &'a Expr) -> Option<(&'static str, &'a Expr)> {
+    let ExprKind::Call {
+        func,
+        args,
+        keywords,
+    } = &expr.node
+    else {
+        return None;
+    };
+    if args.len() != 1 || !keywords.is_empty() {
+        return None;
+    }
+    let ExprKind::Name { id, .. } = &func.node else {
+        return None;
+    };
+    // As in `infer_type`, don't suggest removing a call that isn't actually
+    // the builtin (e.g. a locally shadowed `str`/`int`/`float`).
+    if !checker.is_builtin(id) {
+        return None;
+    }
+    let conversion = match id.as_str() {
+        "str" => "str",
+        "int" => "int",
+        "float" => "float",
+        _ => return None,
+    };
+    Some((conversion, &args[0]))
+}
+
+/// Return `true` if wrapping `inner` in `conversion()` is redundant given
+/// the matching spec's conversion type.
+///
+/// `str(x)` is always redundant against `%s`: the `%` machinery calls
+/// `str()` on its argument regardless of its type. `int(x)`/`float(x)` are
+/// different -- unlike `%s`, `%d`/`%f` require the argument to *already* be
+/// numeric (`"%d" % "5"` raises `TypeError`, while `"%d" % int("5")` works),
+/// so removing the conversion would change behavior unless `inner` is
+/// already known to be numeric.
+fn is_redundant_conversion(checker: &Checker, conversion: &str, format_char: char, inner: &Expr) -> bool {
+    match conversion {
+        "str" => format_char == 's',
+        "int" => {
+            matches!(format_char, 'n' | 'd' | 'b' | 'o' | 'x' | 'X')
+                && matches!(infer_type(checker, inner), DataType::Integer | DataType::Number)
+        }
+        "float" => {
+            matches!(format_char, 'e' | 'E' | 'f' | 'F' | 'g' | 'G')
+                && matches!(infer_type(checker, inner), DataType::Float | DataType::Number)
+        }
+        _ => false,
+    }
+}
+
+/// Flag `str()`/`int()`/`float()` conversions wrapping a `%` argument that
+/// the matching format spec already performs.
+fn check_redundant_conversions(
+    checker: &mut Checker,
+    formats: &[CFormatStrOrBytes<String>],
+    right: &Expr,
+) {
+    let specs = collect_specs(formats);
+    let elts: Vec<&Expr> = match &right.node {
+        ExprKind::Tuple { elts, .. } => elts.iter().collect(),
+        _ if specs.len() == 1 => vec![right],
+        _ => return,
+    };
+    for (format, elt) in specs.iter().zip(elts) {
+        let Some((conversion, inner)) = redundant_conversion_call(checker, elt) else {
+            continue;
+        };
+        if is_redundant_conversion(checker, conversion, format.format_char, inner) {
+            checker.diagnostics.push(Diagnostic::new(
+                RedundantStringFormatConversion {
+                    conversion: conversion.to_string(),
+                },
+                Range::from_located(elt),
+            ));
+        }
+    }
+}
+
+/// Return the number of positional values `format` consumes from the tuple:
+/// one for the conversion itself, plus one more for each of its width and
+/// precision when they're given as `*` (read from the tuple at runtime).
+fn spec_arg_count(format: &CFormatSpec) -> usize {
+    let mut count = 1;
+    if matches!(format.min_field_width, Some(CFormatQuantity::FromValue)) {
+        count += 1;
+    }
+    if matches!(format.precision, Some(CFormatQuantity::FromValue)) {
+        count += 1;
+    }
+    count
+}
+
+/// Flag argument-count and mapping-key mismatches between a `%` format
+/// string and its right-hand operand. `is_valid_tuple`/`is_valid_dict`
+/// deliberately bail out (return `true`) on these cases to avoid false
+/// positives on the *type* check; this is the arity counterpart.
+fn check_format_arity(
+    checker: &mut Checker,
+    expr: &Expr,
+    formats: &[CFormatStrOrBytes<String>],
+    right: &Expr,
+) {
+    let specs = collect_specs(formats);
+    let (mapping, positional): (Vec<_>, Vec<_>) = specs
+        .iter()
+        .partition(|format| format.mapping_key.is_some());
+
+    if !mapping.is_empty() && !positional.is_empty() {
+        checker.diagnostics.push(Diagnostic::new(
+            BadStringFormatArity {
+                reason: "Cannot mix positional and mapping (`%(name)s`) format specs".to_string(),
+            },
+            Range::from_located(expr),
+        ));
+        return;
+    }
+
+    match &right.node {
+        // Only a tuple *literal* lets us count the supplied values
+        // statically; skip it if unpacking (`*a`) makes that count unknown.
+        ExprKind::Tuple { elts, .. }
+            if !elts
+                .iter()
+                .any(|elt| matches!(elt.node, ExprKind::Starred { .. })) =>
+        {
+            // An all-mapping template (`"%(a)s"`) against a tuple operand
+            // isn't an arity mismatch -- `%` requires a mapping here and
+            // raises `TypeError: format requires a mapping` regardless of
+            // the tuple's length, so `expected == 0` would be misleading.
+            if !mapping.is_empty() {
+                checker.diagnostics.push(Diagnostic::new(
+                    BadStringFormatArity {
+                        reason: "Format string requires a mapping, not a tuple".to_string(),
+                    },
+                    Range::from_located(expr),
+                ));
+                return;
+            }
+            let expected: usize = positional.iter().map(|format| spec_arg_count(format)).sum();
+            if expected != elts.len() {
+                checker.diagnostics.push(Diagnostic::new(
+                    BadStringFormatArity {
+                        reason: format!(
+                            "Format string expects {} argument(s), got {}",
+                            expected,
+                            elts.len()
+                        ),
+                    },
+                    Range::from_located(expr),
+                ));
+            }
+        }
+        ExprKind::Dict { keys, .. } => {
+            let mut supplied = FxHashSet::default();
+            for key in keys {
+                let Some(key) = key else {
+                    // `{**other}` spread; we can't check it.
+                    return;
+                };
+                let ExprKind::Constant {
+                    value: Constant::Str(s),
+                    ..
+                } = &key.node
+                else {
+                    // We can't check non-string keys.
+                    return;
+                };
+                supplied.insert(s.as_str());
+            }
+            let expected: FxHashSet<&str> = mapping
+                .iter()
+                .filter_map(|format| format.mapping_key.as_deref())
+                .collect();
+            // Extra keys in the dict are never an error at runtime -- `%`
+            // only requires that every *named* key be present -- so we only
+            // flag missing keys, not unused ones.
+            for missing in expected.difference(&supplied) {
+                checker.diagnostics.push(Diagnostic::new(
+                    BadStringFormatArity {
+                        reason: format!("Missing format key {missing:?}"),
+                    },
+                    Range::from_located(expr),
+                ));
+            }
+        }
+        // Any other operand (a bare name, call, etc.) may itself evaluate
+        // to a tuple at runtime, so we can't check its arity statically.
+        _ => {}
+    }
+}
+
+/// Return `true` if a single (non-tuple, non-dict, non-constant) operand
+/// aligns with the format type, inferring its [`DataType`] where possible.
+fn is_valid_expr(checker: &Checker, formats: &[CFormatStrOrBytes<String>], expr: &Expr) -> bool {
     let formats = collect_specs(formats);
 
     // If there's more than one format, abort.
     if formats.len() != 1 {
         return true;
     }
+    let format = formats.get(0).unwrap();
 
-    formats.get(0).unwrap().format_char == 's'
+    let inferred = infer_type(checker, expr);
+    if matches!(inferred, DataType::Other) {
+        // We can't infer a type, so don't flag it.
+        return true;
+    }
+    equivalent_types(&inferred, &format.format_char.into())
 }
 
 /// PLE1307
@@ -259,13 +761,28 @@ pub fn bad_string_format_type(checker: &mut Checker, expr: &Expr, right: &Expr)
         };
     }
 
+    // Validate each spec's flags independently of the arguments being
+    // formatted. This is a distinct style issue from a type mismatch, so it
+    // gets its own diagnostic rather than reusing `BadStringFormatType`, and
+    // doesn't short-circuit the checks below.
+    for format in collect_specs(&format_strings) {
+        if has_useless_flags(format) {
+            checker.diagnostics.push(Diagnostic::new(
+                UselessFormatFlags,
+                Range::from_located(expr),
+            ));
+        }
+    }
+
+    check_redundant_conversions(checker, &format_strings, right);
+    check_format_arity(checker, expr, &format_strings, right);
+
     // Parse the parameters.
     let is_valid = match &right.node {
-        ExprKind::Tuple { elts, .. } => is_valid_tuple(&format_strings, elts),
-        ExprKind::Dict { keys, values } => is_valid_dict(&format_strings, keys, values),
+        ExprKind::Tuple { elts, .. } => is_valid_tuple(checker, &format_strings, elts),
+        ExprKind::Dict { keys, values } => is_valid_dict(checker, &format_strings, keys, values),
         ExprKind::Constant { value, .. } => is_valid_constant(&format_strings, value),
-        ExprKind::Name { .. } => true,
-        _ => is_valid_other(&format_strings),
+        _ => is_valid_expr(checker, &format_strings, right),
     };
     if !is_valid {
         checker.diagnostics.push(Diagnostic::new(
@@ -274,3 +791,185 @@ pub fn bad_string_format_type(checker: &mut Checker, expr: &Expr, right: &Expr)
         ));
     }
 }
+
+/// PLE1307
+///
+/// Call from `Checker::visit_expr`'s `ExprKind::Call` arm, passing the
+/// call's own `func`/`args`/`keywords` -- mirroring how that arm already
+/// dispatches to other by-callee checks. Not wired up in this tree: the
+/// `checkers/ast.rs` module that owns that dispatch isn't part of this
+/// snapshot, so this function has no caller here.
+pub fn bad_string_format_type_call(
+    checker: &mut Checker,
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let ExprKind::Attribute { value, attr, .. } = &func.node else {
+        return;
+    };
+    if attr != "format" {
+        return;
+    }
+    let ExprKind::Constant {
+        value: Constant::Str(template),
+        ..
+    } = &value.node
+    else {
+        return;
+    };
+
+    let mut auto_index = 0usize;
+    for field in parse_format_fields(template) {
+        let arg = if field.name.is_empty() {
+            let arg = args.get(auto_index);
+            auto_index += 1;
+            arg
+        } else {
+            resolve_format_arg(field.name, args, keywords)
+        };
+        let Some(arg) = arg else {
+            continue;
+        };
+        let Some(spec) = field.spec else {
+            continue;
+        };
+        let Some(type_char) = new_style_type_char(spec) else {
+            continue;
+        };
+        let inferred = infer_type(checker, arg);
+        if matches!(inferred, DataType::Other) {
+            continue;
+        }
+        if !equivalent_types(&inferred, &type_char.into()) {
+            checker.diagnostics.push(Diagnostic::new(
+                BadStringFormatType,
+                Range::from_located(expr),
+            ));
+            return;
+        }
+    }
+}
+
+/// PLE1307
+///
+/// Call from `Checker::visit_expr`'s `ExprKind::JoinedStr` arm. Not wired
+/// up in this tree for the same reason as `bad_string_format_type_call`
+/// above: the `checkers/ast.rs` dispatch module isn't part of this
+/// snapshot.
+pub fn bad_string_format_type_fstring(checker: &mut Checker, expr: &Expr) {
+    let ExprKind::JoinedStr { values } = &expr.node else {
+        return;
+    };
+    for value in values {
+        let ExprKind::FormattedValue {
+            value: inner,
+            format_spec,
+            ..
+        } = &value.node
+        else {
+            continue;
+        };
+        let Some(format_spec) = format_spec else {
+            continue;
+        };
+        let Some(spec) = format_spec_as_str(format_spec) else {
+            continue;
+        };
+        let Some(type_char) = new_style_type_char(&spec) else {
+            continue;
+        };
+        let inferred = infer_type(checker, inner);
+        if matches!(inferred, DataType::Other) {
+            continue;
+        }
+        if !equivalent_types(&inferred, &type_char.into()) {
+            checker.diagnostics.push(Diagnostic::new(
+                BadStringFormatType,
+                Range::from_located(expr),
+            ));
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_format_fields_skips_escaped_braces() {
+        let fields = parse_format_fields("{{literal}} {0}");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "0");
+        assert_eq!(fields[0].spec, None);
+    }
+
+    #[test]
+    fn parse_format_fields_splits_conversion_and_spec() {
+        let fields = parse_format_fields("{0!r:>10}");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "0");
+        assert_eq!(fields[0].spec, Some(">10"));
+    }
+
+    #[test]
+    fn parse_format_fields_handles_nested_spec() {
+        let fields = parse_format_fields("{0:{1}}");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "0");
+        assert_eq!(fields[0].spec, Some("{1}"));
+    }
+
+    #[test]
+    fn parse_format_fields_keeps_unterminated_field() {
+        let fields = parse_format_fields("prefix {0");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "0");
+    }
+
+    #[test]
+    fn new_style_type_char_reads_trailing_presentation_type() {
+        assert_eq!(new_style_type_char(">10.2f"), Some('f'));
+    }
+
+    #[test]
+    fn new_style_type_char_rejects_strftime_style_specs() {
+        // `"{:%Y-%m-%d}".format(some_date)` -- the trailing `d` here isn't
+        // the integer presentation type, it's part of a `__format__`
+        // pattern, so this must not be mistaken for `DataType::Number`.
+        assert_eq!(new_style_type_char("%Y-%m-%d"), None);
+    }
+
+    #[test]
+    fn spec_arg_count_counts_star_width_and_precision() {
+        // `%*.*f` consumes three tuple values: width, precision, and value.
+        let format = CFormatString::from_str("%*.*f").unwrap();
+        let specs = collect_specs(std::slice::from_ref(&format));
+        assert_eq!(spec_arg_count(specs[0]), 3);
+    }
+
+    #[test]
+    fn spec_arg_count_defaults_to_one() {
+        let format = CFormatString::from_str("%d").unwrap();
+        let specs = collect_specs(std::slice::from_ref(&format));
+        assert_eq!(spec_arg_count(specs[0]), 1);
+    }
+
+    #[test]
+    fn has_useless_flags_flags_sign_on_string_conversion() {
+        let format = CFormatString::from_str("%+s").unwrap();
+        let specs = collect_specs(std::slice::from_ref(&format));
+        assert!(has_useless_flags(specs[0]));
+    }
+
+    #[test]
+    fn has_useless_flags_allows_precision_on_integer() {
+        // `"%.3d" % 5 == "005"` -- precision zero-pads integers, so it's
+        // meaningful and must not be flagged.
+        let format = CFormatString::from_str("%.3d").unwrap();
+        let specs = collect_specs(std::slice::from_ref(&format));
+        assert!(!has_useless_flags(specs[0]));
+    }
+}